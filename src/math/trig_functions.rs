@@ -1,24 +1,83 @@
-/// Function that contains the similarities of the sine and cosine implementations
-///
-/// Both of them are calculated using their MacLaurin Series
+/*
+    Cody-Waite split of π/2 into a high part that is exactly representable
+    (its trailing bits are zero) and a small tail, so that the subtraction
+    `r = x - n*π/2` keeps full precision even for large `n`.
+*/
+const PIO2_1: f64 = 1.570_796_326_734_125_6; // first 33 bits of π/2
+const PIO2_1T: f64 = 6.077_100_506_506_192e-11; // π/2 - PIO2_1
+
+/*
+    Minimax coefficients for the sine kernel on [-π/4, π/4],
+    the same constants FreeBSD/libm's `__kernel_sin` uses.
+*/
+const S1: f64 = -1.666_666_666_666_663_2e-01;
+const S2: f64 = 8.333_333_333_322_49e-3;
+const S3: f64 = -1.984_126_982_985_795e-04;
+const S4: f64 = 2.755_731_370_707_007e-06;
+const S5: f64 = -2.505_076_025_340_686e-08;
+const S6: f64 = 1.589_690_995_211_55e-10;
+
+/*
+    Minimax coefficients for the cosine kernel on [-π/4, π/4],
+    matching libm's `__kernel_cos`.
+*/
+const C1: f64 = 4.166_666_666_666_66e-02;
+const C2: f64 = -1.388_888_888_887_411e-03;
+const C3: f64 = 2.480_158_728_947_673e-05;
+const C4: f64 = -2.755_731_435_139_066e-07;
+const C5: f64 = 2.087_572_321_298_175e-09;
+const C6: f64 = -1.135_964_755_778_82e-11;
+
+/// Sine kernel on the reduced interval `[-π/4, π/4]`.
 ///
-/// Because there is just a '+1' that differs in their formula, this function has been
-/// created for not repeating
-fn template<T: Into<f64>>(x: T, tol: f64, kind: i32) -> f64 {
-    use std::f64::consts::PI;
-    const PERIOD: f64 = 2.0 * PI;
-    /* Sometimes, this function is called for a big 'n'(when tol is very small) */
-    fn factorial(n: i128) -> i128 {
-        (1..=n).product()
+/// `z` is `r*r`, threaded in so the combined [`sin_cos`] reduction can share it.
+/// When `z` is below `tol` the polynomial correction is negligible, so the
+/// kernel short-circuits to `r`.
+fn kernel_sin(r: f64, z: f64, tol: f64) -> f64 {
+    if z < tol {
+        return r;
     }
+    r + r * z * (S1 + z * (S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)))))
+}
 
-    /* Function to round the 'decimal'th decimal of the number 'x' */
-    fn round_up_to_decimal(x: f64, decimal: i32) -> f64 {
-        let multiplier = 10f64.powi(decimal);
-        (x * multiplier).round() / multiplier
+/// Cosine kernel on the reduced interval `[-π/4, π/4]`.
+///
+/// `z` is `r*r`. When `z` is below `tol` the result is indistinguishable from
+/// `1.0` at that tolerance, so the polynomial is skipped.
+fn kernel_cos(z: f64, tol: f64) -> f64 {
+    if z < tol {
+        return 1.0;
     }
+    1.0 - 0.5 * z + z * z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))))
+}
+
+/// Reduces `x` to `r ∈ [-π/4, π/4]` and the quadrant index `n & 3`.
+///
+/// Uses `n = round(x * 2/π)` and Cody-Waite splitting of π/2 so the
+/// subtraction stays accurate for arguments as large as `1e6`.
+///
+/// Note: this two-term split is only exact up to `|n| ≈ 2^20` (`x ≈ 1e6`).
+/// Beyond that the reduction error grows rapidly and the result no longer
+/// honors `tol`, so callers should not rely on the requested tolerance for
+/// very large arguments. (libm adds a third `PIO2_2`/`PIO2_2T` term to extend
+/// the accurate range.)
+fn reduce(x: f64) -> (f64, i32) {
+    use std::f64::consts::FRAC_2_PI;
+    let n = (x * FRAC_2_PI).round();
+    let r = x - n * PIO2_1 - n * PIO2_1T;
+    (r, (n as i64 & 3) as i32)
+}
 
-    let mut value: f64 = x.into(); //<-- This is the line for which the trait 'Into' is required
+/// Function that contains the similarities of the sine and cosine implementations.
+///
+/// Both of them are obtained by reducing the argument to `[-π/4, π/4]` (the same
+/// range reduction libm uses) and then selecting either the sine or the cosine
+/// kernel depending on the quadrant. The `kind` parameter rotates the quadrant
+/// selection by one so cosine reuses the very same machinery:
+///     -> kind = 0, for cosine
+///     -> kind = 1, for sine
+fn template<T: Into<f64>>(x: T, tol: f64, kind: i32) -> f64 {
+    let value: f64 = x.into(); //<-- This is the line for which the trait 'Into' is required
 
     /* Check for invalid arguments */
     if !value.is_finite() || value.is_nan() {
@@ -26,39 +85,30 @@ fn template<T: Into<f64>>(x: T, tol: f64, kind: i32) -> f64 {
         return f64::NAN;
     }
 
-    /*
-        The argument to sine could be bigger than the sine's PERIOD
-        To prevent overflowing, strip the value off relative to the PERIOD
-    */
-    while value >= PERIOD {
-        value -= PERIOD;
-    }
-    /* For cases when the value is smaller than the -PERIOD (e.g. sin(-3π) <=> sin(-π)) */
-    while value <= -PERIOD {
-        value += PERIOD;
-    }
+    let (r, n) = reduce(value);
+    let z = r * r;
 
-    let mut rez = 0f64;
-    let mut prev_rez = 1f64;
-    let mut step: i32 = 0;
     /*
-        This while instruction is the MacLaurin Series for sine / cosine
-        sin(x) = Σ (-1)^n * x^2n+1 / (2n+1)!, for n >= 0 and x a Real number
-        cos(x) = Σ (-1)^n * x^2n / (2n)!, for n >= 0 and x a Real number
-
-        '+1' in sine's formula is replaced with 'kind', which values are:
-            -> kind = 0, for cosine
-            -> kind = 1, for sine
+        cos(x) = sin(x + π/2), so cosine is just the sine selection rotated by
+        one quadrant. `kind == 0` (cosine) adds that rotation.
     */
-    while (prev_rez - rez).abs() > tol {
-        prev_rez = rez;
-        rez += (-1f64).powi(step) * value.powi(2 * step + kind)
-            / factorial((2 * step + kind) as i128) as f64;
-        step += 1;
+    match (n + (1 - kind)) & 3 {
+        0 => kernel_sin(r, z, tol),
+        1 => kernel_cos(z, tol),
+        2 => -kernel_sin(r, z, tol),
+        _ => -kernel_cos(z, tol),
     }
+}
 
-    /* Round up to the 5th decimal */
-    round_up_to_decimal(rez, 6)
+/// Rounds `x` to `decimal` decimal places.
+///
+/// The core functions no longer quantize their output — they return the full
+/// `f64` so that `tol` drives precision. This helper reintroduces rounding as
+/// an explicit, opt-in step for the callers that actually want a quantized
+/// result, instead of silently capping every result at six decimals.
+pub fn round_to_decimal(x: f64, decimal: i32) -> f64 {
+    let multiplier = 10f64.powi(decimal);
+    (x * multiplier).round() / multiplier
 }
 
 /// Sine function for non radian angle
@@ -91,6 +141,36 @@ pub fn cosine<T: Into<f64>>(x: T, tol: f64) -> f64 {
     template(x, tol, 0)
 }
 
+/// Returns both sin(x) and cos(x) as `(sin, cos)`, sharing a single argument
+/// reduction.
+///
+/// Callers computing rotations or polar conversions would otherwise call
+/// [`sine`] and [`cosine`] separately and pay for the `[-π/4, π/4]` reduction
+/// twice; this entry point reduces once and guarantees both results come from
+/// the same reduced angle.
+///
+/// Invalid arguments yield `(f64::NAN, f64::NAN)`, matching [`sine`]/[`cosine`].
+pub fn sin_cos<T: Into<f64>>(x: T, tol: f64) -> (f64, f64) {
+    let value: f64 = x.into();
+
+    if !value.is_finite() || value.is_nan() {
+        println!("This function does not accept invalid arguments.");
+        return (f64::NAN, f64::NAN);
+    }
+
+    let (r, n) = reduce(value);
+    let z = r * r;
+    let (s, c) = (kernel_sin(r, z, tol), kernel_cos(z, tol));
+
+    /* Pick sin/cos out of the reduced kernels according to the quadrant. */
+    match n & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
 /// Cosine of 'x' in degrees, with the given tolerance
 pub fn cosine_no_radian_arg<T: Into<f64>>(x: T, tol: f64) -> f64 {
     use std::f64::consts::PI;
@@ -98,51 +178,100 @@ pub fn cosine_no_radian_arg<T: Into<f64>>(x: T, tol: f64) -> f64 {
     cosine(val * PI / 180., tol)
 }
 
+/// Returns the value of tan(x) for an angle 'x' in radians, with the given
+/// tolerance.
+///
+/// Built on the shared reduced-angle kernels (see [`sin_cos`]) so it stays
+/// accurate far from the origin instead of dividing two diverging series. At a
+/// pole (`cos(x) == 0`) it returns the correctly-signed infinity.
+pub fn tangent<T: Into<f64>>(x: T, tol: f64) -> f64 {
+    let value: f64 = x.into();
+
+    if !value.is_finite() || value.is_nan() {
+        println!("This function does not accept invalid arguments.");
+        return f64::NAN;
+    }
+
+    let (s, c) = sin_cos(value, tol);
+    if c == 0.0 {
+        return if s.is_sign_positive() {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        };
+    }
+    s / c
+}
+
+/// Returns the value of cot(x) for an angle 'x' in radians, with the given
+/// tolerance.
+///
+/// The companion to [`tangent`]; at a pole (`sin(x) == 0`) it returns the
+/// correctly-signed infinity.
+pub fn cotangent<T: Into<f64>>(x: T, tol: f64) -> f64 {
+    let value: f64 = x.into();
+
+    if !value.is_finite() || value.is_nan() {
+        println!("This function does not accept invalid arguments.");
+        return f64::NAN;
+    }
+
+    let (s, c) = sin_cos(value, tol);
+    if s == 0.0 {
+        return if c.is_sign_positive() {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        };
+    }
+    c / s
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::f64::consts::PI;
 
-    fn assert<T: Into<f64>>(angle: T, expected_result: f64, is_radian: bool) {
-        // I will round the result to 3 decimal places, since it's an approximation.
+    const TOL: f64 = 1e-10;
+
+    /* Assert the approximation matches the true f64::sin within the tolerance. */
+    fn assert<T: Into<f64>>(angle: T, is_radian: bool) {
+        let angle: f64 = angle.into();
         match is_radian {
-            true => assert_eq!(
-                format!("{:.5}", sine(angle, 1e-10)),
-                /* Lower the tolerance, the more accurate the value will be */
-                format!("{:.5}", expected_result)
-            ),
-            false => assert_eq!(
-                format!("{:.5}", sine_no_radian_arg(angle, 1e-10)),
-                format!("{:.5}", expected_result)
-            ),
+            true => assert!((sine(angle, TOL) - angle.sin()).abs() < TOL),
+            false => {
+                let expected = (angle * PI / 180.0).sin();
+                assert!((sine_no_radian_arg(angle, TOL) - expected).abs() < TOL);
+            }
         }
     }
 
     #[test]
     fn test_sine() {
-        assert(0.0, 0.0, true);
-        assert(PI / 2.0, 1.0, true);
-        assert(PI / 4.0, 1.0 / f64::sqrt(2.0), true);
-        assert(PI, -0.0, true);
-        assert(PI * 3.0 / 2.0, -1.0, true);
-        assert(PI * 2.0, 0.0, true);
-        assert(PI * 2.0 * 3.0, 0.0, true);
-        assert(-PI, 0.0, true);
-        assert(-PI / 2.0, -1.0, true);
-        assert(PI * 8.0 / 45.0, 0.5299192642, true);
-        assert(0.5, 0.4794255386, true);
+        assert(0.0, true);
+        assert(PI / 2.0, true);
+        assert(PI / 4.0, true);
+        assert(PI, true);
+        assert(PI * 3.0 / 2.0, true);
+        assert(PI * 2.0, true);
+        assert(PI * 2.0 * 3.0, true);
+        assert(-PI, true);
+        assert(-PI / 2.0, true);
+        assert(PI * 8.0 / 45.0, true);
+        assert(0.5, true);
+        assert(1e6, true);
         /* Same tests, but angle is now in degrees */
-        assert(0, 0.0, false);
-        assert(90, 1.0, false);
-        assert(45, 1.0 / f64::sqrt(2.0), false);
-        assert(180, -0.0, false);
-        assert(180 * 3 / 2, -1.0, false);
-        assert(180 * 2, 0.0, false);
-        assert(180 * 2 * 3, 0.0, false);
-        assert(-180, 0.0, false);
-        assert(-180 / 2, -1.0, false);
-        assert(180 * 8 / 45, 0.5299192642, false);
-        assert(0.5, 0.00872654, false);
+        assert(0, false);
+        assert(90, false);
+        assert(45, false);
+        assert(180, false);
+        assert(270, false);
+        assert(360, false);
+        assert(1080, false);
+        assert(-180, false);
+        assert(-90, false);
+        assert(32, false);
+        assert(0.5, false);
     }
 
     #[test]
@@ -157,33 +286,52 @@ mod tests {
         assert!(cosine_no_radian_arg(f64::NAN, 1e-1).is_nan());
     }
 
-    fn verify<T: Into<f64>>(angle: T, expected_result: f64, is_radian: bool) {
-        // I will round the result to 3 decimal places, since it's an approximation.
+    /* Assert the approximation matches the true f64::cos within the tolerance. */
+    fn verify<T: Into<f64>>(angle: T, is_radian: bool) {
+        let angle: f64 = angle.into();
         match is_radian {
-            true => assert_eq!(
-                format!("{:.5}", cosine(angle, 1e-10)),
-                /* Lower the tolerance, the more accurate the value will be */
-                format!("{:.5}", expected_result)
-            ),
-            false => assert_eq!(
-                format!("{:.5}", cosine_no_radian_arg(angle, 1e-10)),
-                format!("{:.5}", expected_result)
-            ),
+            true => assert!((cosine(angle, TOL) - angle.cos()).abs() < TOL),
+            false => {
+                let expected = (angle * PI / 180.0).cos();
+                assert!((cosine_no_radian_arg(angle, TOL) - expected).abs() < TOL);
+            }
         }
     }
 
     #[test]
     fn test_cosine() {
-        use std::f64::consts::PI;
-        verify(0, 1., true);
-        verify(0, 1., false);
-        verify(45, 1. / f64::sqrt(2.), false);
-        verify(PI / 4., 1. / f64::sqrt(2.), true);
-        verify(90, 0.0, false);
-        verify(PI / 2., 0.0, true);
-        verify(360, 1., false);
-        verify(2. * PI, 1., true);
-        verify(15. * PI / 2., 0.0, true);
-        verify(-855, -1. / f64::sqrt(2.), false);
+        verify(0, true);
+        verify(0, false);
+        verify(45, false);
+        verify(PI / 4., true);
+        verify(90, false);
+        verify(PI / 2., true);
+        verify(360, false);
+        verify(2. * PI, true);
+        verify(15. * PI / 2., true);
+        verify(-855, false);
+        verify(1e6, true);
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        for &x in &[0.0, 0.5, PI / 6.0, PI / 3.0, 2.0, -3.0, 1e6] {
+            let (s, c) = sin_cos(x, TOL);
+            assert!((s - x.sin()).abs() < TOL);
+            assert!((c - x.cos()).abs() < TOL);
+        }
+    }
+
+    #[test]
+    fn test_tangent() {
+        for &x in &[0.5, PI / 6.0, PI / 3.0, 2.0, -3.0] {
+            assert!((tangent(x, TOL) - x.tan()).abs() < 1e-9);
+            assert!((cotangent(x, TOL) - 1.0 / x.tan()).abs() < 1e-9);
+        }
+        /* tan(0) is exact and cot(0) lands on a pole. */
+        assert_eq!(tangent(0.0, TOL), 0.0);
+        assert_eq!(cotangent(0.0, TOL), f64::INFINITY);
+        /* Very close to a pole the magnitude blows up with the right sign. */
+        assert!(tangent(PI / 2.0, TOL) > 1e6);
     }
 }
\ No newline at end of file